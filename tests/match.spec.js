@@ -67,6 +67,16 @@ describe('matchPath', () => {
         expect(result.matched).toBe(true);
     });
 
+    test('root route matches empty and double-slash pathnames', () => {
+        expect(matchPath('/', '').matched).toBe(true);
+        expect(matchPath('/', '//').matched).toBe(true);
+    });
+
+    test('empty pathname does not match non-root route', () => {
+        const result = matchPath('/about', '');
+        expect(result.matched).toBe(false);
+    });
+
     test('empty path segments are filtered', () => {
         const result = matchPath('//about//', '/about');
         expect(result.matched).toBe(true);
@@ -89,6 +99,17 @@ describe('matchRoute', () => {
         expect(result.params).toEqual({});
     });
 
+    test('root edge pathnames resolve to root route', () => {
+        const edges = ['/', '//', ''];
+
+        for (let i = 0; i < edges.length; i++) {
+            const result = matchRoute(routes, edges[i]);
+            expect(result).not.toBeNull();
+            expect(result.route.path).toBe('/');
+            expect(result.params).toEqual({});
+        }
+    });
+
     test('matches static route', () => {
         const result = matchRoute(routes, '/about');
         expect(result).not.toBeNull();